@@ -1,12 +1,13 @@
 //! Generators may generate bytes or, in general, data, for inputs.
 
-use alloc::vec::Vec;
+use alloc::{format, string::ToString, vec::Vec};
 use core::{cmp::min, marker::PhantomData};
 
 use crate::{
     bolts::rands::Rand,
+    corpus::Corpus,
     inputs::{bytes::BytesInput, Input},
-    state::HasRand,
+    state::{HasCorpus, HasRand},
     Error,
 };
 
@@ -21,6 +22,75 @@ pub use nautilus::*;
 /// The maximum size of dummy bytes generated by _dummy generator methods
 const DUMMY_BYTES_MAX: usize = 64;
 
+/// Precision used when drawing a uniform `[0, 1)` float from the `below` primitive
+/// offered by [`Rand`]; good enough for alias-method lookups and distribution
+/// sampling without needing `f64::EPSILON`-level precision.
+const UNIT_INTERVAL_PRECISION: u64 = 1 << 32;
+
+/// Draws a uniform float in `(0, 1]` from `rand`, suitable as the `u` term in
+/// inverse-CDF sampling (never `0`, so it is safe to feed to `ln`).
+fn rand_unit_interval<R: Rand>(rand: &mut R) -> f64 {
+    (rand.below(UNIT_INTERVAL_PRECISION) + 1) as f64 / UNIT_INTERVAL_PRECISION as f64
+}
+
+/// Controls how a generator picks the length of each generated input. The default,
+/// [`LengthDistribution::Uniform`], matches the historical behavior of drawing
+/// `below(max_size)` and raising a `0` to `1`; the other variants let a
+/// fuzzer bias towards short or long inputs to better explore the length dimension.
+#[derive(Clone, Debug)]
+pub enum LengthDistribution {
+    /// A uniform draw in `[0, max_size)` with `0` raised to `1`: lengths in
+    /// `[2, max_size)` are equally likely, `1` is twice as likely, and `max_size`
+    /// itself is never picked.
+    Uniform,
+    /// Exponential decay with rate `lambda`, favoring short inputs.
+    Geometric {
+        /// The rate parameter; larger values bias more strongly towards short inputs.
+        lambda: f64,
+    },
+    /// A Gaussian centered at `mean` with standard deviation `stddev`, sampled via
+    /// the Box–Muller transform.
+    Normal {
+        /// The center of the distribution.
+        mean: f64,
+        /// The standard deviation of the distribution.
+        stddev: f64,
+    },
+    /// `trials` independent coin flips at probability `p`, favoring lengths near
+    /// `trials * p`.
+    Binomial {
+        /// The number of coin flips to sum.
+        trials: u64,
+        /// The probability of each individual flip.
+        p: f64,
+    },
+}
+
+impl LengthDistribution {
+    /// Draws a length in `[1, max_size]` according to this distribution.
+    /// `max_size` must be greater than 0; callers are expected to enforce this
+    /// at construction time.
+    fn sample<R: Rand>(&self, rand: &mut R, max_size: usize) -> usize {
+        let raw = match self {
+            LengthDistribution::Uniform => rand.below(max_size as u64) as f64,
+            LengthDistribution::Geometric { lambda } => {
+                let u = rand_unit_interval(rand);
+                (-u.ln() / lambda).floor()
+            }
+            LengthDistribution::Normal { mean, stddev } => {
+                let u1 = rand_unit_interval(rand);
+                let u2 = rand_unit_interval(rand);
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos();
+                (mean + z * stddev).round()
+            }
+            LengthDistribution::Binomial { trials, p } => {
+                (0..*trials).filter(|_| rand_unit_interval(rand) <= *p).count() as f64
+            }
+        };
+        raw.max(1.0).min(max_size as f64) as usize
+    }
+}
+
 /// Generators can generate ranges of bytes.
 pub trait Generator<I, S>
 where
@@ -40,6 +110,7 @@ where
     S: HasRand,
 {
     max_size: usize,
+    length_distribution: LengthDistribution,
     phantom: PhantomData<S>,
 }
 
@@ -48,10 +119,9 @@ where
     S: HasRand,
 {
     fn generate(&mut self, state: &mut S) -> Result<BytesInput, Error> {
-        let mut size = state.rand_mut().below(self.max_size as u64);
-        if size == 0 {
-            size = 1;
-        }
+        let size = self
+            .length_distribution
+            .sample(state.rand_mut(), self.max_size);
         let random_bytes: Vec<u8> = (0..size)
             .map(|_| state.rand_mut().below(256) as u8)
             .collect();
@@ -69,11 +139,24 @@ impl<S> RandBytesGenerator<S>
 where
     S: HasRand,
 {
-    /// Returns a new [`RandBytesGenerator`], generating up to `max_size` random bytes.
+    /// Returns a new [`RandBytesGenerator`], generating up to `max_size` random bytes,
+    /// with lengths picked uniformly.
     #[must_use]
     pub fn new(max_size: usize) -> Self {
+        Self::new_with_length_distribution(max_size, LengthDistribution::Uniform)
+    }
+
+    /// Returns a new [`RandBytesGenerator`], generating up to `max_size` random bytes,
+    /// with lengths picked according to `length_distribution`.
+    #[must_use]
+    pub fn new_with_length_distribution(
+        max_size: usize,
+        length_distribution: LengthDistribution,
+    ) -> Self {
+        assert!(max_size > 0, "max_size must be greater than 0");
         Self {
             max_size,
+            length_distribution,
             phantom: PhantomData,
         }
     }
@@ -86,6 +169,7 @@ where
     S: HasRand,
 {
     max_size: usize,
+    length_distribution: LengthDistribution,
     phantom: PhantomData<S>,
 }
 
@@ -94,10 +178,9 @@ where
     S: HasRand,
 {
     fn generate(&mut self, state: &mut S) -> Result<BytesInput, Error> {
-        let mut size = state.rand_mut().below(self.max_size as u64);
-        if size == 0 {
-            size = 1;
-        }
+        let size = self
+            .length_distribution
+            .sample(state.rand_mut(), self.max_size);
         let printables = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz \t\n!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~".as_bytes();
         let random_bytes: Vec<u8> = (0..size)
             .map(|_| *state.rand_mut().choose(printables))
@@ -116,16 +199,741 @@ impl<S> RandPrintablesGenerator<S>
 where
     S: HasRand,
 {
-    /// Creates a new [`RandPrintablesGenerator`], generating up to `max_size` random printable characters.
+    /// Creates a new [`RandPrintablesGenerator`], generating up to `max_size` random
+    /// printable characters, with lengths picked uniformly.
     #[must_use]
     pub fn new(max_size: usize) -> Self {
+        Self::new_with_length_distribution(max_size, LengthDistribution::Uniform)
+    }
+
+    /// Creates a new [`RandPrintablesGenerator`], generating up to `max_size` random
+    /// printable characters, with lengths picked according to `length_distribution`.
+    #[must_use]
+    pub fn new_with_length_distribution(
+        max_size: usize,
+        length_distribution: LengthDistribution,
+    ) -> Self {
+        assert!(max_size > 0, "max_size must be greater than 0");
         Self {
             max_size,
+            length_distribution,
             phantom: PhantomData,
         }
     }
 }
 
+/// A table precomputed with [Vose's alias method](https://www.keithschwarz.com/darts-dice-coins/),
+/// allowing `O(1)` weighted sampling of `n` items after an `O(n)` setup.
+#[derive(Clone, Debug)]
+struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Builds the alias table for the given (not necessarily normalized) `weights`.
+    fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        let sum: f64 = weights.iter().sum();
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w / sum * n as f64).collect();
+
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+        for (i, s) in scaled.iter().enumerate() {
+            if *s < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        let mut prob = vec![1.0; n];
+        let mut alias = vec![0; n];
+
+        while let (Some(l), Some(g)) = (small.pop(), large.pop()) {
+            prob[l] = scaled[l];
+            alias[l] = g;
+            scaled[g] = scaled[g] + scaled[l] - 1.0;
+            if scaled[g] < 1.0 {
+                small.push(g);
+            } else {
+                large.push(g);
+            }
+        }
+
+        // Leftover indices (rounding slack) are fair, unconditional picks.
+        for i in large {
+            prob[i] = 1.0;
+        }
+        for i in small {
+            prob[i] = 1.0;
+        }
+
+        Self { prob, alias }
+    }
+
+    /// Draws a single weighted index in `O(1)`.
+    fn sample<R: Rand>(&self, rand: &mut R) -> usize {
+        let n = self.prob.len();
+        let i = rand.below(n as u64) as usize;
+        let f = rand.below(UNIT_INTERVAL_PRECISION) as f64 / UNIT_INTERVAL_PRECISION as f64;
+        if f < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Generates bytes (or, given a token dictionary, whole tokens) sampled according
+/// to a caller-provided weight table, so fuzzers can bias generation toward the
+/// byte values or dictionary entries that have historically reached new coverage.
+pub struct WeightedBytesGenerator<S>
+where
+    S: HasRand,
+{
+    max_size: usize,
+    items: Vec<Vec<u8>>,
+    table: AliasTable,
+    phantom: PhantomData<S>,
+}
+
+impl<S> Generator<BytesInput, S> for WeightedBytesGenerator<S>
+where
+    S: HasRand,
+{
+    fn generate(&mut self, state: &mut S) -> Result<BytesInput, Error> {
+        let mut size = state.rand_mut().below(self.max_size as u64) as usize;
+        if size == 0 {
+            size = 1;
+        }
+        let mut bytes = Vec::with_capacity(size);
+        while bytes.len() < size {
+            let idx = self.table.sample(state.rand_mut());
+            bytes.extend_from_slice(&self.items[idx]);
+        }
+        bytes.truncate(size);
+        Ok(BytesInput::new(bytes))
+    }
+
+    /// Generates up to `DUMMY_BYTES_MAX` non-random dummy bytes (0)
+    fn generate_dummy(&self, _state: &mut S) -> BytesInput {
+        let size = min(self.max_size, DUMMY_BYTES_MAX);
+        BytesInput::new(vec![0; size])
+    }
+}
+
+impl<S> WeightedBytesGenerator<S>
+where
+    S: HasRand,
+{
+    /// Returns a new [`WeightedBytesGenerator`], generating up to `max_size` bytes
+    /// where each byte value `0..256` is drawn according to `weights` (which must
+    /// have exactly 256 entries). The alias table is built once here and reused
+    /// across calls to `generate`.
+    #[must_use]
+    pub fn new(max_size: usize, weights: &[f64]) -> Self {
+        assert!(max_size > 0, "max_size must be greater than 0");
+        assert_eq!(weights.len(), 256, "weights must cover all 256 byte values");
+        let items = (0..=255_u8).map(|b| vec![b]).collect();
+        Self {
+            max_size,
+            items,
+            table: AliasTable::new(weights),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a new [`WeightedBytesGenerator`] that instead samples whole tokens
+    /// from `tokens`, one `weights` entry per token, concatenating draws until a
+    /// length picked as in [`LengthDistribution::Uniform`] is reached; the last
+    /// token is cut off where it overshoots that length.
+    #[must_use]
+    pub fn with_tokens(max_size: usize, tokens: Vec<Vec<u8>>, weights: &[f64]) -> Self {
+        assert!(max_size > 0, "max_size must be greater than 0");
+        assert!(!tokens.is_empty(), "tokens must not be empty");
+        assert!(
+            tokens.iter().all(|token| !token.is_empty()),
+            "tokens must not contain empty entries"
+        );
+        assert_eq!(
+            tokens.len(),
+            weights.len(),
+            "tokens and weights must have the same length"
+        );
+        Self {
+            max_size,
+            items: tokens,
+            table: AliasTable::new(weights),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Generates inputs by concatenating `min_tokens..=max_tokens` tokens (capped at the
+/// dictionary's size) drawn without
+/// replacement from a user-supplied dictionary, useful for grammar-free structured
+/// fuzzing of keyword-heavy formats.
+pub struct TokenGenerator<S>
+where
+    S: HasRand,
+{
+    dictionary: Vec<Vec<u8>>,
+    min_tokens: usize,
+    max_tokens: usize,
+    separator: Vec<u8>,
+    phantom: PhantomData<S>,
+}
+
+impl<S> Generator<BytesInput, S> for TokenGenerator<S>
+where
+    S: HasRand,
+{
+    fn generate(&mut self, state: &mut S) -> Result<BytesInput, Error> {
+        let span = self.max_tokens - self.min_tokens + 1;
+        let k = self.min_tokens + state.rand_mut().below(span as u64) as usize;
+        let k = min(k, self.dictionary.len());
+
+        let mut bytes = Vec::new();
+        for (i, idx) in self.reservoir_sample(state, k).into_iter().enumerate() {
+            if i > 0 {
+                bytes.extend_from_slice(&self.separator);
+            }
+            bytes.extend_from_slice(&self.dictionary[idx]);
+        }
+        Ok(BytesInput::new(bytes))
+    }
+
+    /// Generates a non-random dummy input by joining the first `min_tokens`
+    /// dictionary entries (or fewer, if the dictionary is smaller).
+    fn generate_dummy(&self, _state: &mut S) -> BytesInput {
+        let k = min(self.min_tokens, self.dictionary.len());
+        let mut bytes = Vec::new();
+        for (i, token) in self.dictionary.iter().take(k).enumerate() {
+            if i > 0 {
+                bytes.extend_from_slice(&self.separator);
+            }
+            bytes.extend_from_slice(token);
+        }
+        BytesInput::new(bytes)
+    }
+}
+
+impl<S> TokenGenerator<S>
+where
+    S: HasRand,
+{
+    /// Returns a new [`TokenGenerator`], concatenating between `min_tokens` and
+    /// `max_tokens` (inclusive) tokens drawn without replacement from `dictionary`,
+    /// joined by `separator`. The count is capped at the dictionary's size.
+    #[must_use]
+    pub fn new(
+        dictionary: Vec<Vec<u8>>,
+        min_tokens: usize,
+        max_tokens: usize,
+        separator: Vec<u8>,
+    ) -> Self {
+        assert!(!dictionary.is_empty(), "dictionary must not be empty");
+        assert!(
+            min_tokens <= max_tokens,
+            "min_tokens must not exceed max_tokens"
+        );
+        Self {
+            dictionary,
+            min_tokens,
+            max_tokens,
+            separator,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Picks `k` dictionary indices without replacement in a single `O(n)` pass,
+    /// filling a reservoir with the first `k` indices and then, for each later
+    /// index `j`, swapping it in at a uniformly drawn slot `below(j + 1)` when that
+    /// slot falls inside the reservoir. This avoids allocating a shuffled copy of
+    /// the (potentially large) dictionary.
+    fn reservoir_sample(&self, state: &mut S, k: usize) -> Vec<usize> {
+        let mut reservoir: Vec<usize> = (0..k).collect();
+        for j in k..self.dictionary.len() {
+            let r = state.rand_mut().below((j + 1) as u64) as usize;
+            if r < k {
+                reservoir[r] = j;
+            }
+        }
+        reservoir
+    }
+}
+
+/// How a sampled numeric field is serialized into the generated [`BytesInput`].
+#[derive(Clone, Debug)]
+pub enum NumericEncoding {
+    /// Rounds the field to an integer and writes it as an ASCII decimal string.
+    AsciiDecimal,
+    /// Rounds the field to an integer and writes it as an ASCII hex string.
+    Hex,
+    /// Writes the field's raw little-endian `f64` bytes.
+    RawLeBytes,
+}
+
+/// The statistical distribution a single numeric field is sampled from.
+#[derive(Clone, Debug)]
+pub enum NumericDistribution {
+    /// Uniform in `[min, max]`.
+    Uniform {
+        /// Lower bound, exclusive.
+        min: f64,
+        /// Upper bound, inclusive.
+        max: f64,
+    },
+    /// Gaussian, sampled via the Box–Muller transform.
+    Normal {
+        /// The center of the distribution.
+        mean: f64,
+        /// The standard deviation of the distribution.
+        stddev: f64,
+    },
+    /// Exponential decay with rate `lambda`, sampled via inverse-CDF.
+    Exponential {
+        /// The rate parameter.
+        lambda: f64,
+    },
+    /// Gamma with the given `shape` and `scale`, sampled as the sum of `shape`
+    /// (rounded up) unit-rate exponential draws, each scaled by `scale`.
+    Gamma {
+        /// The shape parameter; rounded up to the nearest integer number of
+        /// exponential draws to sum.
+        shape: f64,
+        /// The scale parameter.
+        scale: f64,
+    },
+    /// Poisson with the given `mean`, sampled via Knuth's algorithm.
+    Poisson {
+        /// The mean (and variance) of the distribution.
+        mean: f64,
+    },
+}
+
+impl NumericDistribution {
+    /// Draws a single value from this distribution.
+    fn sample<R: Rand>(&self, rand: &mut R) -> f64 {
+        match self {
+            NumericDistribution::Uniform { min, max } => min + rand_unit_interval(rand) * (max - min),
+            NumericDistribution::Normal { mean, stddev } => {
+                let u1 = rand_unit_interval(rand);
+                let u2 = rand_unit_interval(rand);
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos();
+                mean + z * stddev
+            }
+            NumericDistribution::Exponential { lambda } => -rand_unit_interval(rand).ln() / lambda,
+            NumericDistribution::Gamma { shape, scale } => {
+                let draws = shape.ceil().max(1.0) as u64;
+                let sum: f64 = (0..draws).map(|_| -rand_unit_interval(rand).ln()).sum();
+                sum * scale
+            }
+            NumericDistribution::Poisson { mean } => {
+                let l = (-mean).exp();
+                let mut k = 0u64;
+                let mut p = 1.0;
+                loop {
+                    k += 1;
+                    p *= rand_unit_interval(rand);
+                    if p <= l {
+                        break;
+                    }
+                }
+                (k - 1) as f64
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Generates [`BytesInput`]s consisting of numeric values sampled from
+/// per-field statistical distributions, targeting fuzzers of numeric parsers,
+/// CSV readers, and protobuf varints rather than uniform random noise.
+pub struct NumericGenerator<S>
+where
+    S: HasRand,
+{
+    fields: Vec<NumericDistribution>,
+    encoding: NumericEncoding,
+    phantom: PhantomData<S>,
+}
+
+impl<S> NumericGenerator<S>
+where
+    S: HasRand,
+{
+    /// Returns a new [`NumericGenerator`] sampling each of `fields` independently
+    /// and serializing the results according to `encoding`.
+    #[must_use]
+    pub fn new(fields: Vec<NumericDistribution>, encoding: NumericEncoding) -> Self {
+        assert!(!fields.is_empty(), "fields must not be empty");
+        Self {
+            fields,
+            encoding,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Encodes a single sampled `value` according to `self.encoding`.
+    fn encode_field(&self, value: f64) -> Vec<u8> {
+        match self.encoding {
+            NumericEncoding::AsciiDecimal => (value.round() as i64).to_string().into_bytes(),
+            NumericEncoding::Hex => format!("{:x}", value.round() as i64).into_bytes(),
+            NumericEncoding::RawLeBytes => value.to_le_bytes().to_vec(),
+        }
+    }
+}
+
+impl<S> Generator<BytesInput, S> for NumericGenerator<S>
+where
+    S: HasRand,
+{
+    fn generate(&mut self, state: &mut S) -> Result<BytesInput, Error> {
+        let mut bytes = Vec::new();
+        for (i, dist) in self.fields.iter().enumerate() {
+            if i > 0 && !matches!(self.encoding, NumericEncoding::RawLeBytes) {
+                bytes.push(b' ');
+            }
+            let value = dist.sample(state.rand_mut());
+            bytes.extend_from_slice(&self.encode_field(value));
+        }
+        Ok(BytesInput::new(bytes))
+    }
+
+    /// Generates a non-random dummy input by encoding a `0` for every field.
+    fn generate_dummy(&self, _state: &mut S) -> BytesInput {
+        let mut bytes = Vec::new();
+        for (i, _) in self.fields.iter().enumerate() {
+            if i > 0 && !matches!(self.encoding, NumericEncoding::RawLeBytes) {
+                bytes.push(b' ');
+            }
+            bytes.extend_from_slice(&self.encode_field(0.0));
+        }
+        BytesInput::new(bytes)
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Generates bytes by learning an order-1 Markov model of byte transitions from
+/// the current corpus, so early-stage fuzzing produces inputs that resemble valid
+/// data rather than uniform random noise. Falls back to uniform bytes when the
+/// corpus is empty.
+pub struct CorpusByteFrequencyGenerator<S>
+where
+    S: HasRand,
+{
+    /// Distribution of the first byte of each generated input.
+    marginal: AliasTable,
+    /// `transitions[prev as usize]` samples the byte that follows `prev`.
+    transitions: Vec<AliasTable>,
+    max_size: usize,
+    length_distribution: LengthDistribution,
+    phantom: PhantomData<S>,
+}
+
+impl<S> CorpusByteFrequencyGenerator<S>
+where
+    S: HasRand + HasCorpus<BytesInput>,
+{
+    /// Scans every [`BytesInput`] currently in `state`'s corpus to build the byte
+    /// frequency and transition tables, generating up to `max_size` bytes per call
+    /// with lengths picked uniformly. Falls back to a uniform byte distribution if
+    /// the corpus is empty.
+    pub fn from_corpus(state: &S, max_size: usize) -> Result<Self, Error> {
+        Self::from_corpus_with_length_distribution(state, max_size, LengthDistribution::Uniform)
+    }
+
+    /// As [`Self::from_corpus`], but with an explicit [`LengthDistribution`].
+    pub fn from_corpus_with_length_distribution(
+        state: &S,
+        max_size: usize,
+        length_distribution: LengthDistribution,
+    ) -> Result<Self, Error> {
+        assert!(max_size > 0, "max_size must be greater than 0");
+
+        // Tables with no observations (every table, for an empty corpus) fall
+        // back to a uniform distribution so each still has a valid alias table.
+        let mut marginal_counts = [0.0_f64; 256];
+        let mut transition_counts = vec![[0.0_f64; 256]; 256];
+
+        // Corpus ids are monotonically increasing and never reassigned, so a
+        // culled/minimized corpus can leave gaps below `count()`; walk the live
+        // id chain instead of assuming a dense `0..count()` range.
+        let mut id = state.corpus().first();
+        while let Some(current) = id {
+            let testcase = state.corpus().get(current)?;
+            let bytes = testcase.borrow_mut().load_input()?.bytes().to_vec();
+            if let Some((&first, rest)) = bytes.split_first() {
+                marginal_counts[first as usize] += 1.0;
+                let mut prev = first;
+                for &b in rest {
+                    transition_counts[prev as usize][b as usize] += 1.0;
+                    prev = b;
+                }
+            }
+            id = state.corpus().next(current);
+        }
+
+        let table_or_uniform = |counts: &[f64; 256]| {
+            if counts.iter().sum::<f64>() > 0.0 {
+                AliasTable::new(counts)
+            } else {
+                AliasTable::new(&[1.0; 256])
+            }
+        };
+        let marginal = table_or_uniform(&marginal_counts);
+        let transitions = transition_counts.iter().map(table_or_uniform).collect();
+
+        Ok(Self {
+            marginal,
+            transitions,
+            max_size,
+            length_distribution,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Rebuilds the frequency and transition tables from `state`'s corpus,
+    /// allowing the model to track a corpus that keeps growing during a run.
+    pub fn refresh(&mut self, state: &S) -> Result<(), Error> {
+        let rebuilt = Self::from_corpus_with_length_distribution(
+            state,
+            self.max_size,
+            self.length_distribution.clone(),
+        )?;
+        self.marginal = rebuilt.marginal;
+        self.transitions = rebuilt.transitions;
+        Ok(())
+    }
+}
+
+impl<S> Generator<BytesInput, S> for CorpusByteFrequencyGenerator<S>
+where
+    S: HasRand,
+{
+    fn generate(&mut self, state: &mut S) -> Result<BytesInput, Error> {
+        let size = self
+            .length_distribution
+            .sample(state.rand_mut(), self.max_size);
+        let mut bytes = Vec::with_capacity(size);
+        let mut prev = self.marginal.sample(state.rand_mut()) as u8;
+        bytes.push(prev);
+        while bytes.len() < size {
+            prev = self.transitions[prev as usize].sample(state.rand_mut()) as u8;
+            bytes.push(prev);
+        }
+        Ok(BytesInput::new(bytes))
+    }
+
+    /// Generates up to `DUMMY_BYTES_MAX` non-random dummy bytes (0)
+    fn generate_dummy(&self, _state: &mut S) -> BytesInput {
+        let size = min(self.max_size, DUMMY_BYTES_MAX);
+        BytesInput::new(vec![0; size])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bolts::rands::StdRand,
+        corpus::{InMemoryCorpus, Testcase},
+    };
+
+    struct TestState {
+        rand: StdRand,
+    }
+
+    impl HasRand for TestState {
+        type Rand = StdRand;
+
+        fn rand(&self) -> &StdRand {
+            &self.rand
+        }
+
+        fn rand_mut(&mut self) -> &mut StdRand {
+            &mut self.rand
+        }
+    }
+
+    fn test_state() -> TestState {
+        TestState {
+            rand: StdRand::with_seed(0),
+        }
+    }
+
+    struct TestStateWithCorpus {
+        rand: StdRand,
+        corpus: InMemoryCorpus<BytesInput>,
+    }
+
+    impl HasRand for TestStateWithCorpus {
+        type Rand = StdRand;
+
+        fn rand(&self) -> &StdRand {
+            &self.rand
+        }
+
+        fn rand_mut(&mut self) -> &mut StdRand {
+            &mut self.rand
+        }
+    }
+
+    impl HasCorpus<BytesInput> for TestStateWithCorpus {
+        type Corpus = InMemoryCorpus<BytesInput>;
+
+        fn corpus(&self) -> &InMemoryCorpus<BytesInput> {
+            &self.corpus
+        }
+
+        fn corpus_mut(&mut self) -> &mut InMemoryCorpus<BytesInput> {
+            &mut self.corpus
+        }
+    }
+
+    fn test_state_with_corpus() -> TestStateWithCorpus {
+        TestStateWithCorpus {
+            rand: StdRand::with_seed(0),
+            corpus: InMemoryCorpus::new(),
+        }
+    }
+
+    #[test]
+    fn weighted_bytes_generator_biases_towards_high_weight_byte() {
+        let mut weights = [1.0_f64; 256];
+        weights[0x41] = 1_000.0;
+        let mut generator = WeightedBytesGenerator::new(256, &weights);
+        let mut state = test_state();
+
+        let mut high_weight_count = 0;
+        let mut total_bytes = 0;
+        for _ in 0..200 {
+            let input = generator.generate(&mut state).unwrap();
+            total_bytes += input.bytes().len();
+            high_weight_count += input.bytes().iter().filter(|&&b| b == 0x41).count();
+        }
+
+        // With a 1000x weight on a single byte out of 256, the overwhelming
+        // majority of generated bytes should be that byte.
+        assert!(high_weight_count as f64 / total_bytes as f64 > 0.8);
+    }
+
+    #[test]
+    #[should_panic(expected = "tokens must not be empty")]
+    fn weighted_bytes_generator_with_tokens_rejects_empty_dictionary() {
+        WeightedBytesGenerator::<TestState>::with_tokens(16, vec![], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "tokens must not contain empty entries")]
+    fn weighted_bytes_generator_with_tokens_rejects_empty_token() {
+        WeightedBytesGenerator::<TestState>::with_tokens(16, vec![vec![]], &[1.0]);
+    }
+
+    #[test]
+    fn length_distribution_stays_within_bounds() {
+        let mut state = test_state();
+        let max_size = 32;
+        let distributions = [
+            LengthDistribution::Uniform,
+            LengthDistribution::Geometric { lambda: 0.5 },
+            LengthDistribution::Normal {
+                mean: 16.0,
+                stddev: 8.0,
+            },
+            LengthDistribution::Binomial {
+                trials: 64,
+                p: 0.5,
+            },
+        ];
+        for distribution in distributions {
+            for _ in 0..100 {
+                let len = distribution.sample(state.rand_mut(), max_size);
+                assert!((1..=max_size).contains(&len));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "max_size must be greater than 0")]
+    fn rand_bytes_generator_rejects_zero_max_size() {
+        RandBytesGenerator::<TestState>::new(0);
+    }
+
+    #[test]
+    fn token_generator_emits_between_min_and_max_tokens() {
+        let dictionary = vec![b"foo".to_vec(), b"bar".to_vec(), b"baz".to_vec(), b"qux".to_vec()];
+        let mut generator = TokenGenerator::<TestState>::new(dictionary, 1, 3, b",".to_vec());
+        let mut state = test_state();
+
+        for _ in 0..100 {
+            let input = generator.generate(&mut state).unwrap();
+            let token_count = input.bytes().iter().filter(|&&b| b == b',').count() + 1;
+            assert!((1..=3).contains(&token_count));
+        }
+    }
+
+    #[test]
+    fn numeric_generator_encodes_fields_as_ascii_decimal() {
+        let fields = vec![
+            NumericDistribution::Uniform { min: 0.0, max: 9.0 },
+            NumericDistribution::Poisson { mean: 3.0 },
+        ];
+        let mut generator = NumericGenerator::<TestState>::new(fields, NumericEncoding::AsciiDecimal);
+        let mut state = test_state();
+
+        let input = generator.generate(&mut state).unwrap();
+        let text = core::str::from_utf8(input.bytes()).unwrap();
+        let parts: Vec<&str> = text.split(' ').collect();
+        assert_eq!(parts.len(), 2);
+        for part in parts {
+            part.parse::<i64>().unwrap();
+        }
+    }
+
+    #[test]
+    fn corpus_byte_frequency_generator_falls_back_to_uniform_on_empty_corpus() {
+        let mut state = test_state_with_corpus();
+        let mut generator =
+            CorpusByteFrequencyGenerator::from_corpus(&state, 32).unwrap();
+
+        // An empty corpus should still produce well-formed, bounded output instead
+        // of erroring or panicking on a zero-weight alias table.
+        let input = generator.generate(&mut state).unwrap();
+        assert!((1..=32).contains(&input.bytes().len()));
+    }
+
+    #[test]
+    fn corpus_byte_frequency_generator_learns_from_a_singleton_corpus() {
+        let mut state = test_state_with_corpus();
+        state
+            .corpus_mut()
+            .add(Testcase::new(BytesInput::new(vec![b'a'; 64])))
+            .unwrap();
+
+        let mut generator =
+            CorpusByteFrequencyGenerator::from_corpus(&state, 32).unwrap();
+        let input = generator.generate(&mut state).unwrap();
+
+        // With a single repeated byte seed, the first byte and every transition
+        // out of it were only ever observed as `a`, so the vast majority of
+        // sampled bytes should match the corpus's dominant byte.
+        let matching = input.bytes().iter().filter(|&&b| b == b'a').count();
+        assert!(matching * 2 >= input.bytes().len());
+    }
+
+    #[test]
+    #[should_panic(expected = "max_size must be greater than 0")]
+    fn corpus_byte_frequency_generator_rejects_zero_max_size() {
+        let state = test_state_with_corpus();
+        let _ = CorpusByteFrequencyGenerator::from_corpus(&state, 0);
+    }
+}
+
 #[cfg(feature = "python")]
 pub mod pybind {
     use pyo3::prelude::*;